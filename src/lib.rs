@@ -1,10 +1,142 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ffi::OsStr,
-    fs::{copy, create_dir_all, read_dir},
-    path::Path,
+    fs::{self, copy, create_dir_all, read_dir, OpenOptions},
+    hash::Hasher,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
 };
 
+use chrono::{DateTime, Local};
+use log::{error, info, warn};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use siphasher::sip128::{Hasher128, SipHasher13};
+
+/// Resumo de uma execução: quantos arquivos foram vistos, movidos/copiados, simulados (em
+/// modo `dry_run`), ignorados e os erros encontrados no caminho, em vez de abortar na primeira
+/// falha.
+#[derive(Debug, Default)]
+pub struct ExecutionSummary {
+    pub processed: usize,
+    pub moved: usize,
+    pub simulated: usize,
+    pub skipped: usize,
+    pub errors: Vec<std::io::Error>,
+}
+
+// Tamanho do bloco inicial usado no hash parcial, antes de decidir se vale a pena ler o
+// arquivo inteiro para o hash completo.
+const PARTIAL_HASH_BYTES: usize = 4096;
+
+// Nome do arquivo de journal criado dentro do diretório organizado, usado pelo `Organize::undo`.
+const JOURNAL_FILE_NAME: &str = ".organize-journal";
+
+// Um registro do journal: de onde veio e para onde foi cada arquivo organizado, um JSON por linha.
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry {
+    source: PathBuf,
+    destination: PathBuf,
+    operation: OrganizeOperation,
+}
+
+fn journal_path(path: &str) -> PathBuf {
+    Path::new(path).join(JOURNAL_FILE_NAME)
+}
+
+// Registra uma ação já realizada no journal, criando o diretório pai se necessário.
+fn append_journal(path: &str, entry: &JournalEntry) -> std::io::Result<()> {
+    let journal_path = journal_path(path);
+    if let Some(parent) = journal_path.parent() {
+        create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&journal_path)?;
+
+    let line = serde_json::to_string(entry)
+        .map_err(std::io::Error::other)?;
+
+    writeln!(file, "{}", line)
+}
+
+/// Filtro de extensões e profundidade usado ao varrer o diretório alvo.
+///
+/// Quando `allowed` é `Some`, apenas extensões presentes nele são aceitas; `excluded`
+/// é sempre aplicado, mesmo quando uma extensão também está em `allowed`.
+#[derive(Debug, Default, Clone)]
+pub struct TraversalOptions {
+    recursive: bool,
+    allowed: Option<HashSet<&'static str>>,
+    excluded: HashSet<&'static str>,
+}
+
+impl TraversalOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Faz a varredura descer recursivamente pelas subpastas do diretório alvo.
+    pub fn set_recursive(&mut self, recursive: bool) {
+        self.recursive = recursive;
+    }
+
+    /// Restringe a varredura às extensões informadas; `None` aceita qualquer extensão.
+    pub fn set_allowed(&mut self, allowed: Option<HashSet<&'static str>>) {
+        self.allowed = allowed;
+    }
+
+    /// Ignora arquivos cuja extensão esteja neste conjunto.
+    pub fn set_excluded(&mut self, excluded: HashSet<&'static str>) {
+        self.excluded = excluded;
+    }
+
+    fn accepts(&self, extension: Option<&str>) -> bool {
+        if extension.is_some_and(|ext| self.excluded.contains(ext)) {
+            return false;
+        }
+
+        match &self.allowed {
+            Some(allowed) => extension.is_some_and(|ext| allowed.contains(ext)),
+            None => true,
+        }
+    }
+}
+
+/// Determina se os arquivos organizados são copiados (mantendo o original) ou movidos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrganizeOperation {
+    Copy,
+    Move,
+}
+
+impl OrganizeOperation {
+    fn verb(self) -> &'static str {
+        match self {
+            OrganizeOperation::Copy => "copiar",
+            OrganizeOperation::Move => "mover",
+        }
+    }
+
+    // Move via `rename` quando possível (mesma partição) e cai para copy+remove quando não,
+    // por exemplo ao mover entre sistemas de arquivos diferentes.
+    fn apply(self, source: &Path, dest: &Path) -> std::io::Result<()> {
+        match self {
+            OrganizeOperation::Copy => copy(source, dest).map(|_| ()),
+            OrganizeOperation::Move => {
+                if fs::rename(source, dest).is_ok() {
+                    return Ok(());
+                }
+                copy(source, dest)?;
+                fs::remove_file(source)
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum OrganizeMethod {
     // Organizar por extensão de arquivo, pordendo configura as pastas e extensões.
@@ -25,67 +157,366 @@ impl OrganizeMethod {
         OrganizeMethod::Extension(extensions)
     }
 
-    fn execute(self, _parallel: bool, path: &'static str) {
-        let files = self.get_files(path);
-
-        if let OrganizeMethod::Extension(extensions) = self {
-            for file_name in files {
-                if let Some(extension) = file_name.split('.').last() {
-                    for (folder_name, ext_list) in &extensions {
-                        if ext_list.contains(&extension) {
-                            let folder_path = Path::new(path).join(folder_name);
-
-                            if let Err(e) = create_dir_all(&folder_path) {
-                                eprintln!(
-                                    "Erro ao criar diretório {}: {}",
-                                    folder_path.display(),
-                                    e
-                                );
-                            }
-
-                            let file_path = Path::new(path).join(&file_name);
-                            if !file_path.exists() {
-                                eprintln!("Arquivo não encontrado: {}", file_path.display());
-                                continue;
-                            }
-
-                            let dest_path =
-                                folder_path.join(file_path.file_name().unwrap_or(OsStr::new("")));
-
-                            if let Err(e) = copy(file_path, &dest_path) {
-                                eprintln!(
-                                    "Erro ao copiar arquivo {} para {}: {}",
-                                    file_name,
-                                    dest_path.display(),
-                                    e
-                                );
-                            }
-                            break;
-                        }
-                    }
+    // Descobre em qual pasta um arquivo deve cair de acordo com o método escolhido.
+    // Retorna `None` quando o arquivo não se encaixa em nenhuma pasta (ex: extensão não mapeada).
+    fn classify(&self, file_path: &Path) -> Option<String> {
+        let file_name = file_path.file_name()?.to_str()?;
+
+        match self {
+            OrganizeMethod::Extension(extensions) => {
+                let extension = file_name.split('.').next_back()?;
+                extensions
+                    .iter()
+                    .find(|(_, ext_list)| ext_list.contains(&extension))
+                    .map(|(folder_name, _)| folder_name.to_string())
+            }
+            OrganizeMethod::Date => {
+                let modified = fs::metadata(file_path).ok()?.modified().ok()?;
+                let datetime: DateTime<Local> = modified.into();
+                Some(datetime.format("%Y-%m").to_string())
+            }
+            OrganizeMethod::Size => {
+                let size = fs::metadata(file_path).ok()?.len();
+                let folder = if size < 1_048_576 {
+                    "Small"
+                } else if size < 104_857_600 {
+                    "Medium"
+                } else {
+                    "Large"
+                };
+                Some(folder.to_string())
+            }
+            OrganizeMethod::Alphabetical => {
+                let first_char = file_name.chars().next()?.to_ascii_uppercase();
+                if first_char.is_ascii_alphabetic() {
+                    Some(first_char.to_string())
+                } else {
+                    Some("#".to_string())
                 }
             }
         }
     }
 
-    fn get_files(&self, path: &'static str) -> Vec<String> {
-        if let OrganizeMethod::Extension(_) = self {
-            let mut file_names = Vec::new();
-            if let Ok(entries) = read_dir(path) {
-                for entry in entries.flatten() {
-                    let file_path = entry.path();
-                    if file_path.is_file() {
-                        if let Some(file_name) = file_path.file_name().and_then(|f| f.to_str()) {
-                            file_names.push(file_name.to_string());
-                        }
-                    }
+    // Roda a organização e retorna um resumo com o que foi processado, movido/copiado, ignorado
+    // e os erros encontrados pelo caminho, sem abortar no primeiro.
+    // Quando `parallel` é `true`, cada arquivo é classificado e copiado/movido em uma thread do rayon.
+    fn execute(
+        self,
+        parallel: bool,
+        traversal: &TraversalOptions,
+        dry_run: bool,
+        operation: OrganizeOperation,
+        path: &'static str,
+    ) -> ExecutionSummary {
+        let files = list_files(path, traversal);
+        let processed = files.len();
+
+        let classify_target = |file_path: PathBuf| -> Option<(PathBuf, PathBuf)> {
+            let folder_name = match self.classify(&file_path) {
+                Some(folder_name) => folder_name,
+                None => {
+                    warn!("Arquivo ignorado (sem classificação): {}", file_path.display());
+                    return None;
                 }
+            };
+
+            let folder_path = Path::new(path).join(folder_name);
+
+            // Já está na pasta de destino correta (ex: reexecução sobre uma árvore já
+            // organizada); pular evita renomear o arquivo para `nome (1).ext` à toa.
+            if file_path.parent() == Some(folder_path.as_path()) {
+                return None;
             }
-            file_names
+
+            Some((file_path, folder_path))
+        };
+
+        let targets: Vec<(PathBuf, PathBuf)> = if parallel {
+            files.into_par_iter().filter_map(&classify_target).collect()
         } else {
-            Vec::new()
+            files.into_iter().filter_map(&classify_target).collect()
+        };
+
+        let skipped = processed - targets.len();
+        let mut errors = Vec::new();
+
+        // Deduplica as pastas de destino antes de criá-las, para não disparar `create_dir_all`
+        // repetido para a mesma pasta quando várias threads organizam arquivos em paralelo.
+        let mut folders: Vec<&PathBuf> = targets.iter().map(|(_, folder)| folder).collect();
+        folders.sort();
+        folders.dedup();
+
+        for folder_path in folders {
+            if dry_run {
+                info!("[dry-run] criaria o diretório {}", folder_path.display());
+                continue;
+            }
+
+            if let Err(e) = create_dir_all(folder_path) {
+                let error = std::io::Error::new(
+                    e.kind(),
+                    format!("Erro ao criar diretório {}: {}", folder_path.display(), e),
+                );
+                error!("{error}");
+                errors.push(error);
+            }
+        }
+
+        // Destinos já reivindicados nesta execução, para que duas threads processando arquivos
+        // de origens diferentes nunca resolvam para o mesmo caminho de destino (veja
+        // `claim_destination`).
+        let claimed_destinations: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+
+        // Serializa as gravações no journal: `append_journal` abre o arquivo em modo append e
+        // escreve uma linha por vez, o que não é atômico entre threads e pode intercalar/corromper
+        // entradas quando várias chamadas de `place_one` gravam ao mesmo tempo.
+        let journal_lock: Mutex<()> = Mutex::new(());
+
+        // `Ok(true)` quando a ação foi realmente executada e `Ok(false)` quando apenas simulada
+        // (`dry_run`), para que o resumo nunca reporte um dry-run como um arquivo movido de verdade.
+        let place_one = |file_path: &PathBuf, folder_path: &PathBuf| -> Result<bool, std::io::Error> {
+            if !file_path.exists() {
+                let error = std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("Arquivo não encontrado: {}", file_path.display()),
+                );
+                error!("{error}");
+                return Err(error);
+            }
+
+            let wanted_dest = folder_path.join(file_path.file_name().unwrap_or(OsStr::new("")));
+            let dest_path = claim_destination(&wanted_dest, &claimed_destinations);
+
+            if dry_run {
+                info!(
+                    "[dry-run] {} {} para {}",
+                    operation.verb(),
+                    file_path.display(),
+                    dest_path.display()
+                );
+                return Ok(false);
+            }
+
+            operation.apply(file_path, &dest_path).map_err(|e| {
+                let error = std::io::Error::new(
+                    e.kind(),
+                    format!(
+                        "Erro ao {} arquivo {} para {}: {}",
+                        operation.verb(),
+                        file_path.display(),
+                        dest_path.display(),
+                        e
+                    ),
+                );
+                error!("{error}");
+                error
+            })?;
+
+            info!("{} {} -> {}", operation.verb(), file_path.display(), dest_path.display());
+
+            let entry = JournalEntry {
+                source: file_path.clone(),
+                destination: dest_path.clone(),
+                operation,
+            };
+            let journal_result = {
+                let _guard = journal_lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                append_journal(path, &entry)
+            };
+            if let Err(e) = journal_result {
+                warn!("Falha ao registrar no journal: {e}");
+            }
+
+            Ok(true)
+        };
+
+        let results: Vec<Result<bool, std::io::Error>> = if parallel {
+            targets
+                .par_iter()
+                .map(|(file_path, folder_path)| place_one(file_path, folder_path))
+                .collect()
+        } else {
+            targets
+                .iter()
+                .map(|(file_path, folder_path)| place_one(file_path, folder_path))
+                .collect()
+        };
+
+        let mut moved = 0;
+        let mut simulated = 0;
+        for result in results {
+            match result {
+                Ok(true) => moved += 1,
+                Ok(false) => simulated += 1,
+                Err(e) => errors.push(e),
+            }
+        }
+
+        ExecutionSummary {
+            processed,
+            moved,
+            simulated,
+            skipped,
+            errors,
+        }
+    }
+
+}
+
+// Lista os arquivos de um diretório, usado tanto pelos métodos de organização quanto pela
+// busca de duplicatas. Desce pelas subpastas quando `traversal.recursive` é `true`, e descarta
+// arquivos cuja extensão não passe pelo filtro allowed/excluded.
+fn list_files(path: &str, traversal: &TraversalOptions) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::from(path)];
+
+    while let Some(dir) = pending.pop() {
+        let entries = match read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+
+            if entry_path.is_dir() {
+                if traversal.recursive {
+                    pending.push(entry_path);
+                }
+                continue;
+            }
+
+            if !entry_path.is_file() {
+                continue;
+            }
+
+            // O journal é um arquivo de controle interno, nunca um arquivo organizável: sem essa
+            // checagem ele acaba sendo classificado e movido como qualquer outro arquivo (o que
+            // vale especialmente para `Date`/`Size`/`Alphabetical`, que aceitam qualquer nome),
+            // perdendo o histórico de execuções anteriores.
+            if entry_path.file_name().and_then(OsStr::to_str) == Some(JOURNAL_FILE_NAME) {
+                continue;
+            }
+
+            let extension = entry_path.extension().and_then(OsStr::to_str);
+            if traversal.accepts(extension) {
+                files.push(entry_path);
+            }
+        }
+    }
+
+    files
+}
+
+// Reivindica um caminho de destino livre para `wanted_dest`, acrescentando " (1)", " (2)", etc.
+// antes da extensão quando o caminho já existe ou já foi reivindicado nesta execução.
+//
+// O lock do `claimed` fica preso durante toda a busca + inserção, então duas threads nunca
+// podem "ver" o mesmo caminho livre e escrever por cima uma da outra: apenas checar
+// `Path::exists` entre threads paralelas (sem essa trava) é uma condição de corrida.
+fn claim_destination(wanted_dest: &Path, claimed: &Mutex<HashSet<PathBuf>>) -> PathBuf {
+    let mut claimed = claimed.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let parent = wanted_dest.parent().unwrap_or_else(|| Path::new(""));
+    let stem = wanted_dest
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .unwrap_or_default();
+    let extension = wanted_dest.extension().and_then(OsStr::to_str);
+
+    let mut candidate = wanted_dest.to_path_buf();
+    let mut counter = 1;
+
+    loop {
+        if !candidate.exists() && !claimed.contains(&candidate) {
+            claimed.insert(candidate.clone());
+            return candidate;
+        }
+
+        let candidate_name = match extension {
+            Some(extension) => format!("{stem} ({counter}).{extension}"),
+            None => format!("{stem} ({counter})"),
+        };
+
+        candidate = parent.join(candidate_name);
+        counter += 1;
+    }
+}
+
+// Calcula o hash de até `limit` bytes lidos do início do arquivo.
+fn hash_partial(path: &Path, limit: usize) -> Option<u128> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut buffer = vec![0u8; limit];
+    let mut read_total = 0;
+
+    while read_total < buffer.len() {
+        let read = file.read(&mut buffer[read_total..]).ok()?;
+        if read == 0 {
+            break;
+        }
+        read_total += read;
+    }
+
+    Some(sip_hash128(&buffer[..read_total]))
+}
+
+// Calcula o hash do conteúdo inteiro do arquivo, lido em blocos para não carregar tudo em memória.
+fn hash_full(path: &Path) -> Option<u128> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = SipHasher13::new();
+    let mut buffer = [0u8; 65536];
+
+    loop {
+        let read = file.read(&mut buffer).ok()?;
+        if read == 0 {
+            break;
+        }
+        hasher.write(&buffer[..read]);
+    }
+
+    Some(hash128_to_u128(hasher.finish128()))
+}
+
+fn sip_hash128(bytes: &[u8]) -> u128 {
+    let mut hasher = SipHasher13::new();
+    hasher.write(bytes);
+    hash128_to_u128(hasher.finish128())
+}
+
+fn hash128_to_u128(hash: siphasher::sip128::Hash128) -> u128 {
+    ((hash.h1 as u128) << 64) | hash.h2 as u128
+}
+
+// Agrupa arquivos por tamanho e depois por hash (parcial e, só se necessário, completo),
+// para encontrar duplicatas sem precisar ler o conteúdo de arquivos que não colidem no tamanho.
+fn group_duplicates(files: Vec<PathBuf>) -> HashMap<u128, Vec<PathBuf>> {
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for file in files {
+        if let Ok(metadata) = fs::metadata(&file) {
+            by_size.entry(metadata.len()).or_default().push(file);
+        }
+    }
+
+    let mut by_partial_hash: HashMap<u128, Vec<PathBuf>> = HashMap::new();
+    for group in by_size.into_values().filter(|group| group.len() > 1) {
+        for file in group {
+            if let Some(partial_hash) = hash_partial(&file, PARTIAL_HASH_BYTES) {
+                by_partial_hash.entry(partial_hash).or_default().push(file);
+            }
+        }
+    }
+
+    let mut by_full_hash: HashMap<u128, Vec<PathBuf>> = HashMap::new();
+    for group in by_partial_hash.into_values().filter(|group| group.len() > 1) {
+        for file in group {
+            if let Some(full_hash) = hash_full(&file) {
+                by_full_hash.entry(full_hash).or_default().push(file);
+            }
         }
     }
+
+    by_full_hash.retain(|_, group| group.len() > 1);
+    by_full_hash
 }
 
 #[derive(Debug)]
@@ -97,11 +528,27 @@ pub struct OrganizeOptions {
     // e.g:
     // Para o método de organização por `Extension` será levantado um processo para organizar cada tipo de arquivo, Documentos, Imagens e etc
     parallel: bool,
+
+    // Controla se a varredura desce em subpastas e quais extensões são aceitas/ignoradas.
+    traversal: TraversalOptions,
+
+    // Quando `true`, apenas loga as ações que seriam feitas, sem tocar no sistema de arquivos
+    // nem gravar no journal.
+    dry_run: bool,
+
+    // Determina se os arquivos são copiados ou movidos para a pasta de destino.
+    operation: OrganizeOperation,
 }
 
 impl OrganizeOptions {
     pub fn new(method: OrganizeMethod, parallel: bool) -> Self {
-        Self { method, parallel }
+        Self {
+            method,
+            parallel,
+            traversal: TraversalOptions::default(),
+            dry_run: false,
+            operation: OrganizeOperation::Copy,
+        }
     }
 
     /// Configuração padrão para organizar pastas, vai organizar por `OrganizeMethod::Extension`
@@ -113,6 +560,7 @@ impl OrganizeOptions {
     /// - Audios -> `[".mp3", ".wav", ".flac"]`
     /// - Videos -> `[".mp4", ".mov", ".avi"]`
     /// - Sheets -> `[".csv", ".xlsx", ".ods"]`
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
         let mut default_extensions: HashMap<&'static str, Vec<&'static str>> = HashMap::new();
 
@@ -125,6 +573,9 @@ impl OrganizeOptions {
         Self {
             method: OrganizeMethod::Extension(default_extensions),
             parallel: true,
+            traversal: TraversalOptions::default(),
+            dry_run: false,
+            operation: OrganizeOperation::Copy,
         }
     }
 
@@ -137,6 +588,21 @@ impl OrganizeOptions {
     pub fn set_parallel(&mut self, parallel: bool) {
         self.parallel = parallel;
     }
+
+    /// Especifica as opções de varredura (recursividade e filtro de extensões).
+    pub fn set_traversal(&mut self, traversal: TraversalOptions) {
+        self.traversal = traversal;
+    }
+
+    /// Especifica se a organização deve apenas simular as ações (sem mexer no disco nem no journal).
+    pub fn set_dry_run(&mut self, dry_run: bool) {
+        self.dry_run = dry_run;
+    }
+
+    /// Especifica se os arquivos organizados devem ser copiados ou movidos.
+    pub fn set_operation(&mut self, operation: OrganizeOperation) {
+        self.operation = operation;
+    }
 }
 
 pub struct Organize {
@@ -157,7 +623,65 @@ impl Organize {
         self.options = options;
     }
 
+    /// Encontra grupos de arquivos duplicados no caminho configurado, usando o mesmo filtro
+    /// de varredura (`recursive`/`allowed`/`excluded`) das opções de organização.
+    ///
+    /// Arquivos são comparados primeiro por tamanho, depois por um hash parcial dos primeiros
+    /// bytes e, só em caso de colisão, por um hash do conteúdo inteiro. O resultado mapeia o
+    /// hash final para os `PathBuf`s que compartilham o mesmo conteúdo.
+    pub fn find_duplicates(&self) -> HashMap<u128, Vec<PathBuf>> {
+        let files = list_files(self.path, &self.options.traversal);
+        group_duplicates(files)
+    }
+
+    /// Desfaz as ações registradas no journal (`.organize-journal`) do diretório configurado,
+    /// removendo cada cópia e devolvendo cada arquivo movido ao caminho de origem. Linhas ausentes, corrompidas ou incompletas são
+    /// ignoradas em vez de abortar o undo inteiro, e a ausência do journal não é um erro.
+    pub fn undo(&self) -> Result<(), std::io::Error> {
+        let journal_path = journal_path(self.path);
+
+        let content = match fs::read_to_string(&journal_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        // Desfaz na ordem inversa (mais recente primeiro): quando um arquivo foi organizado mais
+        // de uma vez, o destino de um hop mais antigo pode já ter sido o source de um hop mais
+        // novo, e não existe mais no disco. Desfazendo do mais novo para o mais antigo, cada passo
+        // recria o destino do passo anterior antes dele ser consultado.
+        for line in content.lines().rev() {
+            let entry: JournalEntry = match serde_json::from_str(line) {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+
+            if !entry.destination.exists() {
+                continue;
+            }
+
+            let result = match entry.operation {
+                OrganizeOperation::Copy => fs::remove_file(&entry.destination),
+                OrganizeOperation::Move => {
+                    OrganizeOperation::Move.apply(&entry.destination, &entry.source)
+                }
+            };
+
+            if let Err(e) = result {
+                error!("Erro ao desfazer {}: {}", entry.destination.display(), e);
+            }
+        }
+
+        fs::remove_file(&journal_path).ok();
+
+        Ok(())
+    }
+
     pub fn execute(self) -> Result<(), std::io::Error> {
+        // Inicializa o logger uma única vez; chamadas repetidas (ex: múltiplos `Organize::execute`
+        // no mesmo processo) não devem falhar, por isso o erro é ignorado.
+        let _ = env_logger::try_init();
+
         if self.path.is_empty() {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -165,10 +689,328 @@ impl Organize {
             ));
         }
 
-        self.options
-            .method
-            .execute(self.options.parallel, self.path);
+        let summary = self.options.method.execute(
+            self.options.parallel,
+            &self.options.traversal,
+            self.options.dry_run,
+            self.options.operation,
+            self.path,
+        );
 
-        Ok(())
+        info!(
+            "Organização concluída: {} processados, {} movidos/copiados, {} simulados (dry-run), {} ignorados, {} erros",
+            summary.processed,
+            summary.moved,
+            summary.simulated,
+            summary.skipped,
+            summary.errors.len()
+        );
+
+        if summary.errors.is_empty() {
+            return Ok(());
+        }
+
+        let message = summary
+            .errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        Err(std::io::Error::other(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `Organize`/`OrganizeOptions` trabalham com `&'static str`, então os testes vazam o caminho
+    // do diretório temporário; o `TempDir` retornado precisa ficar vivo até o fim do teste para
+    // que o diretório não seja removido antes da hora.
+    fn temp_dir() -> (tempfile::TempDir, &'static str) {
+        let dir = tempfile::tempdir().expect("falha ao criar diretório temporário");
+        let path: &'static str =
+            Box::leak(dir.path().to_str().expect("caminho inválido").to_string().into_boxed_str());
+        (dir, path)
+    }
+
+    fn documents_extensions() -> HashMap<&'static str, Vec<&'static str>> {
+        let mut extensions = HashMap::new();
+        extensions.insert("Documents", vec!["txt"]);
+        extensions
+    }
+
+    #[test]
+    fn find_duplicates_groups_only_files_with_identical_content() {
+        let (_dir, path) = temp_dir();
+
+        fs::write(Path::new(path).join("a.txt"), b"duplicate content").unwrap();
+        fs::write(Path::new(path).join("b.txt"), b"duplicate content").unwrap();
+        // mesmo tamanho de "a.txt"/"b.txt", mas conteúdo diferente: deve colidir no estágio de
+        // tamanho e ser descartado no estágio de hash parcial/completo.
+        fs::write(Path::new(path).join("c.txt"), b"different content").unwrap();
+        // tamanho diferente de "a.txt"/"b.txt": descartado logo no estágio de tamanho.
+        fs::write(Path::new(path).join("d.txt"), b"duplicate-content ").unwrap();
+
+        let organize = Organize::new(path, OrganizeOptions::new(OrganizeMethod::Date, false));
+        let duplicates = organize.find_duplicates();
+
+        assert_eq!(duplicates.len(), 1, "esperava um único grupo de duplicatas");
+
+        let mut names: Vec<String> = duplicates
+            .values()
+            .next()
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap().to_string())
+            .collect();
+        names.sort();
+
+        assert_eq!(names, vec!["a.txt".to_string(), "b.txt".to_string()]);
+    }
+
+    #[test]
+    fn undo_reverses_a_move_and_restores_the_original_file() {
+        let (_dir, path) = temp_dir();
+        fs::write(Path::new(path).join("report.txt"), b"hello").unwrap();
+
+        let mut options = OrganizeOptions::new(
+            OrganizeMethod::custom_extension(documents_extensions()),
+            false,
+        );
+        options.set_operation(OrganizeOperation::Move);
+
+        Organize::new(path, options).execute().unwrap();
+
+        let source = Path::new(path).join("report.txt");
+        let destination = Path::new(path).join("Documents").join("report.txt");
+        assert!(!source.exists());
+        assert!(destination.exists());
+
+        let options = OrganizeOptions::new(OrganizeMethod::Date, false);
+        Organize::new(path, options).undo().unwrap();
+
+        assert!(source.exists(), "undo deveria ter restaurado o arquivo original");
+        assert!(!destination.exists());
+        assert_eq!(fs::read_to_string(&source).unwrap(), "hello");
+        assert!(!journal_path(path).exists());
+    }
+
+    #[test]
+    fn dry_run_leaves_the_filesystem_untouched() {
+        let (_dir, path) = temp_dir();
+        fs::write(Path::new(path).join("report.txt"), b"hello").unwrap();
+
+        let mut options = OrganizeOptions::new(
+            OrganizeMethod::custom_extension(documents_extensions()),
+            false,
+        );
+        options.set_dry_run(true);
+
+        Organize::new(path, options).execute().unwrap();
+
+        assert!(Path::new(path).join("report.txt").exists());
+        assert!(!Path::new(path).join("Documents").exists());
+        assert!(!journal_path(path).exists());
+    }
+
+    #[test]
+    fn parallel_move_does_not_clobber_same_named_files_from_different_folders() {
+        let (_dir, path) = temp_dir();
+
+        fs::create_dir_all(Path::new(path).join("sub_a")).unwrap();
+        fs::create_dir_all(Path::new(path).join("sub_b")).unwrap();
+        fs::write(Path::new(path).join("sub_a").join("report.txt"), b"from a").unwrap();
+        fs::write(Path::new(path).join("sub_b").join("report.txt"), b"from b").unwrap();
+
+        let mut traversal = TraversalOptions::new();
+        traversal.set_recursive(true);
+
+        let mut options =
+            OrganizeOptions::new(OrganizeMethod::custom_extension(documents_extensions()), true);
+        options.set_traversal(traversal);
+        options.set_operation(OrganizeOperation::Move);
+
+        Organize::new(path, options).execute().unwrap();
+
+        let documents = Path::new(path).join("Documents");
+        let mut contents: Vec<String> = fs::read_dir(&documents)
+            .unwrap()
+            .flatten()
+            .map(|entry| fs::read_to_string(entry.path()).unwrap())
+            .collect();
+        contents.sort();
+
+        // Ambos os arquivos precisam sobreviver com seu conteúdo original: se o destino
+        // colidisse sem o `claim_destination` atômico, um dos dois seria sobrescrito.
+        assert_eq!(contents, vec!["from a".to_string(), "from b".to_string()]);
+    }
+
+    #[test]
+    fn parallel_execution_does_not_interleave_journal_entries() {
+        let (_dir, path) = temp_dir();
+
+        let file_count = 40;
+        for i in 0..file_count {
+            fs::write(Path::new(path).join(format!("file_{i}.txt")), format!("content {i}")).unwrap();
+        }
+
+        let mut options =
+            OrganizeOptions::new(OrganizeMethod::custom_extension(documents_extensions()), true);
+        options.set_operation(OrganizeOperation::Copy);
+
+        Organize::new(path, options).execute().unwrap();
+
+        let journal = fs::read_to_string(journal_path(path)).unwrap();
+        let lines: Vec<&str> = journal.lines().filter(|line| !line.trim().is_empty()).collect();
+
+        // Se as gravações de threads diferentes se intercalassem, algumas linhas não seriam mais
+        // um JSON válido por linha e `from_str` falharia.
+        for line in &lines {
+            serde_json::from_str::<JournalEntry>(line)
+                .unwrap_or_else(|e| panic!("linha de journal corrompida: {line:?} ({e})"));
+        }
+        assert_eq!(lines.len(), file_count);
+    }
+
+    #[test]
+    fn journal_file_is_never_picked_up_as_an_organizable_file() {
+        let (_dir, path) = temp_dir();
+        fs::write(Path::new(path).join("a.txt"), b"hello").unwrap();
+
+        let mut options = OrganizeOptions::new(
+            OrganizeMethod::custom_extension(documents_extensions()),
+            false,
+        );
+        options.set_operation(OrganizeOperation::Move);
+        Organize::new(path, options).execute().unwrap();
+
+        // Uma segunda execução, com um método que classifica qualquer nome de arquivo (incluindo
+        // o próprio journal), não deve mover nem recriar o journal dentro de uma subpasta.
+        let options = OrganizeOptions::new(OrganizeMethod::Alphabetical, false);
+        Organize::new(path, options).execute().unwrap();
+
+        assert!(journal_path(path).exists(), "o journal deveria continuar na raiz");
+        assert!(!Path::new(path).join("#").join(JOURNAL_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn undo_unwinds_a_two_hop_history_newest_first() {
+        let (_dir, path) = temp_dir();
+        fs::write(Path::new(path).join("a.txt"), b"hello").unwrap();
+
+        let mut first_run = OrganizeOptions::new(
+            OrganizeMethod::custom_extension(documents_extensions()),
+            false,
+        );
+        first_run.set_operation(OrganizeOperation::Move);
+        Organize::new(path, first_run).execute().unwrap();
+
+        let first_hop = Path::new(path).join("Documents").join("a.txt");
+        assert!(first_hop.exists());
+
+        // Segunda execução sobre a mesma raiz, agora descendo recursivamente para alcançar o
+        // arquivo já movido para "Documents/", reclassificando-o para uma pasta diferente ("A").
+        let mut extensions = HashMap::new();
+        extensions.insert("A", vec!["txt"]);
+        let mut second_run =
+            OrganizeOptions::new(OrganizeMethod::custom_extension(extensions), false);
+        second_run.set_operation(OrganizeOperation::Move);
+        let mut traversal = TraversalOptions::new();
+        traversal.set_recursive(true);
+        second_run.set_traversal(traversal);
+        Organize::new(path, second_run).execute().unwrap();
+
+        let original = Path::new(path).join("a.txt");
+        let second_hop = Path::new(path).join("A").join("a.txt");
+        assert!(!first_hop.exists());
+        assert!(second_hop.exists());
+
+        let options = OrganizeOptions::new(OrganizeMethod::Date, false);
+        Organize::new(path, options).undo().unwrap();
+
+        assert!(original.exists(), "undo deveria ter restaurado o arquivo na raiz");
+        assert!(!first_hop.exists());
+        assert!(!second_hop.exists());
+    }
+
+    #[test]
+    fn size_method_buckets_files_into_small_and_medium_folders() {
+        let (_dir, path) = temp_dir();
+
+        fs::write(Path::new(path).join("tiny.bin"), vec![0u8; 1024]).unwrap();
+        fs::write(Path::new(path).join("big.bin"), vec![0u8; 2 * 1_048_576]).unwrap();
+
+        let options = OrganizeOptions::new(OrganizeMethod::Size, false);
+        Organize::new(path, options).execute().unwrap();
+
+        assert!(Path::new(path).join("Small").join("tiny.bin").exists());
+        assert!(Path::new(path).join("Medium").join("big.bin").exists());
+    }
+
+    #[test]
+    fn alphabetical_method_buckets_files_by_initial() {
+        let (_dir, path) = temp_dir();
+
+        fs::write(Path::new(path).join("apple.txt"), b"a").unwrap();
+        fs::write(Path::new(path).join("1099.txt"), b"1").unwrap();
+
+        let options = OrganizeOptions::new(OrganizeMethod::Alphabetical, false);
+        Organize::new(path, options).execute().unwrap();
+
+        assert!(Path::new(path).join("A").join("apple.txt").exists());
+        assert!(Path::new(path).join("#").join("1099.txt").exists());
+    }
+
+    #[test]
+    fn date_method_buckets_files_by_year_month() {
+        let (_dir, path) = temp_dir();
+        fs::write(Path::new(path).join("report.txt"), b"hello").unwrap();
+
+        let options = OrganizeOptions::new(OrganizeMethod::Date, false);
+        Organize::new(path, options).execute().unwrap();
+
+        let expected_folder = Local::now().format("%Y-%m").to_string();
+        assert!(Path::new(path).join(expected_folder).join("report.txt").exists());
+    }
+
+    #[test]
+    fn traversal_excluded_skips_files_with_matching_extension() {
+        let (_dir, path) = temp_dir();
+
+        fs::write(Path::new(path).join("keep.txt"), b"keep").unwrap();
+        fs::write(Path::new(path).join("skip.log"), b"skip").unwrap();
+
+        let mut excluded = HashSet::new();
+        excluded.insert("log");
+        let mut traversal = TraversalOptions::new();
+        traversal.set_excluded(excluded);
+
+        let files = list_files(path, &traversal);
+        let names: Vec<String> =
+            files.iter().map(|p| p.file_name().unwrap().to_str().unwrap().to_string()).collect();
+
+        assert!(names.contains(&"keep.txt".to_string()));
+        assert!(!names.contains(&"skip.log".to_string()));
+    }
+
+    #[test]
+    fn traversal_allowed_restricts_to_matching_extensions() {
+        let (_dir, path) = temp_dir();
+
+        fs::write(Path::new(path).join("keep.txt"), b"keep").unwrap();
+        fs::write(Path::new(path).join("skip.log"), b"skip").unwrap();
+
+        let mut allowed = HashSet::new();
+        allowed.insert("txt");
+        let mut traversal = TraversalOptions::new();
+        traversal.set_allowed(Some(allowed));
+
+        let files = list_files(path, &traversal);
+        let names: Vec<String> =
+            files.iter().map(|p| p.file_name().unwrap().to_str().unwrap().to_string()).collect();
+
+        assert_eq!(names, vec!["keep.txt".to_string()]);
     }
 }